@@ -1,9 +1,10 @@
 use std::marker::PhantomData;
+use std::ops::{Deref, Index, IndexMut};
 
 use num_traits::Zero;
 
-use buffer::Pixel;
-use image::{GenericImage, GenericImageView};
+use buffer::{ImageBuffer, Pixel};
+use image::{GenericImage, GenericImageView, ImageError};
 
 /// A flat buffer over a (multi channel) image.
 ///
@@ -16,6 +17,13 @@ pub struct FlatSamples<Buffer> {
     /// Underlying linear container holding sample values.
     pub samples: Buffer,
 
+    /// Offset of the first sample from the start of the buffer.
+    ///
+    /// This is added to every index computed from the other fields, which makes it possible to
+    /// describe a sub-rectangle of a larger buffer (see `crop`) without moving or copying any
+    /// samples.
+    pub offset: usize,
+
     /// The number of channels in the color representation of the image.
     pub channels: u8,
 
@@ -58,6 +66,7 @@ impl<Buffer> FlatSamples<Buffer> {
         // This initialization order is more beautiful <3
         FlatSamples {
             samples: self.samples.as_ref(),
+            offset: self.offset,
             width_stride: self.width_stride,
             height_stride: self.height_stride,
             channel_stride: self.channel_stride,
@@ -71,6 +80,7 @@ impl<Buffer> FlatSamples<Buffer> {
     pub fn as_mut<T>(&mut self) -> FlatSamples<&mut [T]> where Buffer: AsMut<[T]> {
         FlatSamples {
             samples: self.samples.as_mut(),
+            offset: self.offset,
             width_stride: self.width_stride,
             height_stride: self.height_stride,
             channel_stride: self.channel_stride,
@@ -179,16 +189,13 @@ impl<Buffer> FlatSamples<Buffer> {
         }
 
         // Order extents by strides, then check that each is less equal than the next stride.
-        let grouped: [Dim; 3] = [
+        let mut grouped: [Dim; 3] = [
             Dim(self.channel_stride, self.channels as usize),
             Dim(self.width_stride, self.width as usize),
             Dim(self.height_stride, self.height as usize)];
+        grouped.sort();
 
-        let min_dim = grouped.iter().min().unwrap();
-        let max_dim = grouped.iter().max().unwrap();
-        // The smaller of the two largest elements.
-        let mid_dim = (grouped[0].max(grouped[1]))
-            .min(grouped[0].max(grouped[2]));
+        let [min_dim, mid_dim, max_dim] = grouped;
         assert!(min_dim.stride() <= mid_dim.stride() && mid_dim.stride() <= max_dim.stride());
 
         let min_size = match min_dim.len() {
@@ -201,11 +208,16 @@ impl<Buffer> FlatSamples<Buffer> {
             Some(size) => size,
         };
 
-        let _max_size = match max_dim.len() {
+        let max_size = match max_dim.len() {
             None => return true,
-            Some(_) => (), // Only want to know this didn't overflow.
+            Some(size) => size,
         };
 
+        // The offset must not push the last sample out of representable range either.
+        if self.offset.checked_add(max_size).is_none() {
+            return true
+        }
+
         // Each higher dimension must walk over all of one lower dimension.
         min_size > mid_dim.stride() || mid_size > max_dim.stride()
     }
@@ -232,7 +244,7 @@ impl<Buffer> FlatSamples<Buffer> {
             _ => return None,
         };
 
-        Some(0usize)
+        Some(self.offset)
             .and_then(|b| b.checked_add(idx_c))
             .and_then(|b| b.checked_add(idx_x))
             .and_then(|b| b.checked_add(idx_y))
@@ -243,7 +255,437 @@ impl<Buffer> FlatSamples<Buffer> {
     /// The computation can not overflow as we could represent the maximum coordinate.
     pub fn in_bounds_index(&self, x: u32, y: u32, c: u8) -> usize {
         let (y_stride, x_stride, c_stride) = self.strides_hwc();
-        (y as usize * y_stride) + (x as usize * x_stride) + (c as usize * c_stride)
+        self.offset + (y as usize * y_stride) + (x as usize * x_stride) + (c as usize * c_stride)
+    }
+
+    /// Get a descriptor for a sub-rectangle of this buffer.
+    ///
+    /// The returned `FlatSamples` borrows the same underlying samples (a reference-based version,
+    /// as with `as_ref`); only `width`, `height` and `offset` change, all strides stay the same.
+    /// This allows carving a tile out of a larger frame buffer without any allocation.
+    ///
+    /// Returns `None` if `(x, y, width, height)` does not fit within the current bounds.
+    pub fn crop<T>(&self, x: u32, y: u32, width: u32, height: u32) -> Option<FlatSamples<&[T]>>
+        where Buffer: AsRef<[T]>,
+    {
+        let x_end = x.checked_add(width)?;
+        let y_end = y.checked_add(height)?;
+
+        if x_end > self.width || y_end > self.height {
+            return None
+        }
+
+        Some(FlatSamples {
+            samples: self.samples.as_ref(),
+            offset: self.in_bounds_index(x, y, 0),
+            channels: self.channels,
+            channel_stride: self.channel_stride,
+            width,
+            width_stride: self.width_stride,
+            height,
+            height_stride: self.height_stride,
+        })
+    }
+
+    /// Check whether the samples are laid out according to a particular normal form.
+    ///
+    /// This reuses the alias detection of `has_aliased_samples` and additionally checks the
+    /// stride constraints of the requested form.
+    pub fn is_normal(&self, form: NormalForm) -> bool {
+        if self.has_aliased_samples() {
+            return false
+        }
+
+        match form {
+            NormalForm::Unaliased => true,
+            NormalForm::PixelPacked => self.channel_stride == 1,
+            NormalForm::RowMajorPacked => {
+                self.channel_stride == 1
+                    && self.width_stride == self.channels as usize
+                    && self.height_stride == self.channels as usize * self.width as usize
+            },
+        }
+    }
+
+    /// Move or copy the samples into a canonical, row-major packed `ImageBuffer`.
+    ///
+    /// If the buffer is already laid out as `RowMajorPacked` (with a zero `offset`), the
+    /// underlying storage is converted with `Into::into` directly; for an owning `Vec` this is a
+    /// move, not a copy. Otherwise a fresh buffer is allocated and samples are copied into it by
+    /// walking `strides_hwc`. Aliased buffers are rejected with `NormalFormRequired` since there
+    /// is no well defined canonical order for them.
+    pub fn try_into_buffer<P>(self) -> Result<ImageBuffer<P, Vec<P::Subpixel>>, (Error, Self)>
+        where P: Pixel + 'static, Buffer: AsRef<[P::Subpixel]> + Into<Vec<P::Subpixel>>,
+    {
+        if self.has_aliased_samples() {
+            return Err((Error::NormalFormRequired(NormalForm::Unaliased), self))
+        }
+
+        if self.channels != P::channel_count() {
+            return Err((Error::WrongColor, self))
+        }
+
+        // The length must be larger than the maximum index, see `as_view`.
+        if self.samples.as_ref().len() <= self.max_index().unwrap_or(usize::max_value()) {
+            return Err((Error::TooLarge, self))
+        }
+
+        if self.offset == 0 && self.is_normal(NormalForm::RowMajorPacked) {
+            let (width, height) = (self.width, self.height);
+            let buffer = ImageBuffer::from_raw(width, height, self.samples.into())
+                .expect("Match of size and strides was already checked above");
+            return Ok(buffer)
+        }
+
+        let (height, width, channels) = self.extents();
+        let (y_stride, x_stride, c_stride) = self.strides_hwc();
+        let mut packed = Vec::with_capacity(height * width * channels);
+
+        for y in 0..height {
+            for x in 0..width {
+                for c in 0..channels {
+                    let index = self.offset + y*y_stride + x*x_stride + c*c_stride;
+                    packed.push(self.samples.as_ref()[index]);
+                }
+            }
+        }
+
+        let buffer = ImageBuffer::from_raw(self.width, self.height, packed)
+            .expect("The packed buffer was allocated with the canonical size");
+        Ok(buffer)
+    }
+
+    /// Create a buffer where every pixel has the same color.
+    ///
+    /// The resulting buffer is as small as possible, a single pixel repeated over `width` and
+    /// `height` by giving `width_stride` and `height_stride` a value of `0`.
+    pub fn with_monocolor<P>(color: &P, width: u32, height: u32) -> Self
+        where P: Pixel, Buffer: From<Vec<P::Subpixel>>,
+    {
+        FlatSamples {
+            samples: Buffer::from(color.channels().to_vec()),
+            offset: 0,
+            channels: P::channel_count(),
+            channel_stride: 1,
+            width,
+            width_stride: 0,
+            height,
+            height_stride: 0,
+        }
+    }
+
+    /// Create a buffer from samples already in row-major, pixel-packed order.
+    ///
+    /// This is the `RowMajorPacked` normal form: `channel_stride = 1`, `width_stride = channels`,
+    /// `height_stride = channels*width`. It is the layout produced by most decoders and the one
+    /// required by `ImageBuffer`.
+    pub fn from_row_major_packed<P>(samples: Buffer, width: u32, height: u32) -> Self
+        where P: Pixel,
+    {
+        let channels = P::channel_count();
+        FlatSamples {
+            samples,
+            offset: 0,
+            channels,
+            channel_stride: 1,
+            width,
+            width_stride: channels as usize,
+            height,
+            height_stride: channels as usize * width as usize,
+        }
+    }
+
+    /// Create a buffer from samples in planar, channel-first (NCHW) order.
+    ///
+    /// Each channel is a contiguous plane: `channel_stride = width*height`, `width_stride = 1`,
+    /// `height_stride = width`. This is the layout expected by most ML tensors and is the
+    /// normal form that feeds interleave/deinterleave pipelines.
+    pub fn from_planar<P>(samples: Buffer, width: u32, height: u32) -> Self
+        where P: Pixel,
+    {
+        FlatSamples {
+            samples,
+            offset: 0,
+            channels: P::channel_count(),
+            channel_stride: width as usize * height as usize,
+            width,
+            width_stride: 1,
+            height,
+            height_stride: width as usize,
+        }
+    }
+}
+
+impl<P, Container> ImageBuffer<P, Container>
+    where P: Pixel + 'static, P::Subpixel: 'static, Container: Deref<Target=[P::Subpixel]>,
+{
+    /// Get a `FlatSamples` view over this buffer's backing storage.
+    ///
+    /// The returned view is in row-major packed order, the same layout the buffer itself is
+    /// stored in, so this is a free reinterpretation rather than a copy.
+    pub fn as_flat_samples(&self) -> FlatSamples<&[P::Subpixel]> {
+        let (width, height) = self.dimensions();
+        FlatSamples::from_row_major_packed::<P>(&*self, width, height)
+    }
+}
+
+/// Gather the samples of one pixel into an owned pixel value.
+///
+/// Shared by `GenericImageView::get_pixel` and the `Pixels` iterator so that both go through the
+/// same bounds-checked, strided read.
+fn gather_pixel<P, Samples>(inner: &FlatSamples<Samples>, x: u32, y: u32) -> P
+where
+    P: Pixel,
+    Samples: AsRef<[P::Subpixel]>,
+{
+    let image = inner.samples.as_ref();
+    let base_index = inner.in_bounds_index(x, y, 0);
+    let channels = P::channel_count() as usize;
+
+    let mut buffer = [Zero::zero(); 256];
+    buffer.iter_mut().enumerate().take(channels).for_each(|(c, to)| {
+        let index = base_index + c * inner.channel_stride;
+        *to = image[index];
+    });
+
+    P::from_slice(&buffer[..channels]).clone()
+}
+
+/// A row of samples, handed back either as a packed slice or a strided iterator.
+///
+/// Which variant is produced depends only on the layout of the underlying `FlatSamples`: rows
+/// that are contiguous in memory (`channel_stride == 1` and `width_stride == channels`) are
+/// returned as a plain slice, everything else falls back to stepping through the row by
+/// `width_stride`.
+pub enum FlatRow<'a, P: Pixel> {
+    /// A contiguous, packed row.
+    Packed(&'a [P::Subpixel]),
+    /// A row whose pixels are not contiguous in memory.
+    Strided(StridedRow<'a, P>),
+}
+
+/// Iterates the pixels of a single non-contiguous row.
+pub struct StridedRow<'a, P: Pixel> {
+    samples: &'a [P::Subpixel],
+    width_stride: usize,
+    channel_stride: usize,
+    channels: usize,
+    x: u32,
+    width: u32,
+}
+
+impl<'a, P: Pixel> Iterator for StridedRow<'a, P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        if self.x >= self.width {
+            return None;
+        }
+
+        let base = self.x as usize * self.width_stride;
+        let mut buffer = [Zero::zero(); 256];
+        buffer.iter_mut().enumerate().take(self.channels).for_each(|(c, to)| {
+            *to = self.samples[base + c * self.channel_stride];
+        });
+
+        self.x += 1;
+        Some(P::from_slice(&buffer[..self.channels]).clone())
+    }
+}
+
+/// Iterates the rows of a `View`.
+pub struct Rows<'a, P: Pixel> {
+    inner: FlatSamples<&'a [P::Subpixel]>,
+    y: u32,
+}
+
+impl<'a, P: Pixel> Iterator for Rows<'a, P> {
+    type Item = FlatRow<'a, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.inner.height {
+            return None;
+        }
+
+        let channels = self.inner.channels as usize;
+        let base = self.inner.in_bounds_index(0, self.y, 0);
+
+        let row = if self.inner.channel_stride == 1 && self.inner.width_stride == channels {
+            let len = self.inner.width as usize * channels;
+            FlatRow::Packed(&self.inner.samples[base..base + len])
+        } else {
+            FlatRow::Strided(StridedRow {
+                samples: &self.inner.samples[base..],
+                width_stride: self.inner.width_stride,
+                channel_stride: self.inner.channel_stride,
+                channels,
+                x: 0,
+                width: self.inner.width,
+            })
+        };
+
+        self.y += 1;
+        Some(row)
+    }
+}
+
+/// Iterates all pixels of a `View`, together with their coordinates.
+pub struct Pixels<'a, P: Pixel> {
+    inner: FlatSamples<&'a [P::Subpixel]>,
+    x: u32,
+    y: u32,
+}
+
+impl<'a, P: Pixel> Iterator for Pixels<'a, P> {
+    type Item = (u32, u32, P);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.inner.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let pixel = gather_pixel(&self.inner, x, y);
+
+        self.x += 1;
+        if self.x >= self.inner.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        Some((x, y, pixel))
+    }
+}
+
+/// A row of samples of a `ViewMut`, handed back as a packed slice or a strided iterator of
+/// mutable pixels.
+///
+/// See `FlatRow` for the immutable counterpart; the distinction is the same.
+pub enum FlatRowMut<'a, P: Pixel> {
+    /// A contiguous, packed row.
+    Packed(&'a mut [P::Subpixel]),
+    /// A row whose pixels are not contiguous in memory.
+    Strided(StridedRowMut<'a, P>),
+}
+
+/// Iterates the pixels of a single non-contiguous, mutable row.
+pub struct StridedRowMut<'a, P: Pixel> {
+    samples: &'a mut [P::Subpixel],
+    width_stride: usize,
+    channels: usize,
+    x: u32,
+    width: u32,
+}
+
+impl<'a, P: Pixel + 'a> Iterator for StridedRowMut<'a, P> {
+    type Item = &'a mut P;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x >= self.width {
+            return None;
+        }
+
+        let base = self.x as usize * self.width_stride;
+        self.x += 1;
+
+        // Safety: `ViewMut` is only constructed through `as_view_mut`, which already guarantees
+        // that the view is unaliased and pixel-packed (`channel_stride == 1`). Each step here
+        // advances the index by `width_stride`, so the `channels`-long slices handed out never
+        // overlap.
+        let pixel = unsafe {
+            let ptr = self.samples.as_mut_ptr().add(base);
+            ::std::slice::from_raw_parts_mut(ptr, self.channels)
+        };
+
+        Some(P::from_slice_mut(pixel))
+    }
+}
+
+/// Iterates the rows of a `ViewMut`.
+pub struct RowsMut<'a, P: Pixel> {
+    inner: FlatSamples<&'a mut [P::Subpixel]>,
+    y: u32,
+}
+
+impl<'a, P: Pixel> Iterator for RowsMut<'a, P> {
+    type Item = FlatRowMut<'a, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.inner.height {
+            return None;
+        }
+
+        let channels = self.inner.channels as usize;
+        let base = self.inner.in_bounds_index(0, self.y, 0);
+        let width = self.inner.width as usize;
+        let packed = self.inner.channel_stride == 1 && self.inner.width_stride == channels;
+        // The row spans from `base` up to and including the last sample of its last pixel,
+        // never reaching into the next row, so slices handed out for distinct `y` don't overlap.
+        let row_len = if packed {
+            width * channels
+        } else if width == 0 {
+            0
+        } else {
+            (width - 1) * self.inner.width_stride + channels
+        };
+
+        // Safety: see `StridedRowMut::next`; rows at distinct `y` never overlap because
+        // `ViewMut` is guaranteed unaliased, and `row_len` is clipped to this row's own span.
+        let row_samples = unsafe {
+            let ptr = self.inner.samples.as_mut_ptr().add(base);
+            ::std::slice::from_raw_parts_mut(ptr, row_len)
+        };
+
+        let row = if packed {
+            FlatRowMut::Packed(row_samples)
+        } else {
+            FlatRowMut::Strided(StridedRowMut {
+                samples: row_samples,
+                width_stride: self.inner.width_stride,
+                channels,
+                x: 0,
+                width: self.inner.width,
+            })
+        };
+
+        self.y += 1;
+        Some(row)
+    }
+}
+
+/// Iterates all pixels of a `ViewMut`, together with their coordinates.
+pub struct PixelsMut<'a, P: Pixel> {
+    inner: FlatSamples<&'a mut [P::Subpixel]>,
+    x: u32,
+    y: u32,
+}
+
+impl<'a, P: Pixel + 'a> Iterator for PixelsMut<'a, P> {
+    type Item = (u32, u32, &'a mut P);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.inner.height {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let base = self.inner.in_bounds_index(x, y, 0);
+        let channels = P::channel_count() as usize;
+
+        self.x += 1;
+        if self.x >= self.inner.width {
+            self.x = 0;
+            self.y += 1;
+        }
+
+        // Safety: see `StridedRowMut::next`; `ViewMut` guarantees an unaliased, pixel-packed
+        // layout so the `channels`-long slice for `(x, y)` never overlaps another pixel's.
+        let pixel = unsafe {
+            let ptr = self.inner.samples.as_mut_ptr().add(base);
+            ::std::slice::from_raw_parts_mut(ptr, channels)
+        };
+
+        Some((x, y, P::from_slice_mut(pixel)))
     }
 }
 
@@ -264,6 +706,33 @@ where
     phantom: PhantomData<P>,
 }
 
+impl<Buffer, P: Pixel> View<Buffer, P>
+where
+    Buffer: AsRef<[P::Subpixel]>,
+{
+    /// Iterate over the rows of this view.
+    ///
+    /// Rows that are contiguous in memory are handed back as a plain slice; see `FlatRow`.
+    pub fn rows(&self) -> Rows<'_, P> {
+        Rows {
+            inner: self.inner.as_ref(),
+            y: 0,
+        }
+    }
+
+    /// Iterate over all pixels of this view, together with their coordinates.
+    ///
+    /// This avoids recomputing bounds and strides for every pixel the way repeated calls to
+    /// `get_pixel` would.
+    pub fn pixels(&self) -> Pixels<'_, P> {
+        Pixels {
+            inner: self.inner.as_ref(),
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
 /// A mutable owning version of a flat buffer.
 ///
 /// While this wraps a buffer similar to `ImageBuffer`, this is mostly intended as a utility. The
@@ -279,6 +748,35 @@ where
     phantom: PhantomData<P>,
 }
 
+impl<Buffer, P: Pixel> ViewMut<Buffer, P>
+where
+    Buffer: AsMut<[P::Subpixel]> + AsRef<[P::Subpixel]>,
+{
+    /// Iterate over the rows of this view, yielding mutable access to each pixel.
+    ///
+    /// Rows that are contiguous in memory are handed back as a plain mutable slice; see
+    /// `FlatRowMut`.
+    pub fn rows_mut(&mut self) -> RowsMut<'_, P> {
+        RowsMut {
+            inner: self.inner.as_mut(),
+            y: 0,
+        }
+    }
+
+    /// Iterate over all pixels of this view, together with their coordinates, with mutable
+    /// access to each pixel.
+    ///
+    /// This avoids recomputing bounds and strides for every pixel the way repeated calls to
+    /// `get_pixel_mut` would.
+    pub fn pixels_mut(&mut self) -> PixelsMut<'_, P> {
+        PixelsMut {
+            inner: self.inner.as_mut(),
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
 /// Denotes invalid flat sample buffers when trying to convert to stricter types.
 ///
 /// The biggest use case being `ImageBuffer` which expects closely packed
@@ -332,7 +830,25 @@ pub enum NormalForm {
     RowMajorPacked,
 }
 
-// FIXME: Into<ImageError> for Error.
+impl ::std::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Error::TooLarge => write!(f, "The layout is larger than the underlying buffer"),
+            Error::NormalFormRequired(form) =>
+                write!(f, "The layout needs to satisfy {:?} for this operation", form),
+            Error::WrongColor =>
+                write!(f, "The color format did not match the channel count"),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+impl From<Error> for ImageError {
+    fn from(error: Error) -> ImageError {
+        ImageError::FormatError(error.to_string())
+    }
+}
 
 impl<Buffer, P: Pixel> GenericImageView for View<Buffer, P> 
     where Buffer: AsRef<[P::Subpixel]>
@@ -360,17 +876,7 @@ impl<Buffer, P: Pixel> GenericImageView for View<Buffer, P>
             panic!("Image index {:?} out of bounds {:?}", (x, y), (self.inner.width, self.inner.height))
         }
 
-        let image = self.inner.samples.as_ref();
-        let base_index = self.inner.in_bounds_index(x, y, 0);
-        let channels = P::channel_count() as usize;
-
-        let mut buffer = [Zero::zero(); 256];
-        buffer.iter_mut().enumerate().take(channels).for_each(|(c, to)| {
-            let index = base_index + c*self.inner.channel_stride;
-            *to = image[index];
-        });
-
-        P::from_slice(&buffer[..channels]).clone()
+        gather_pixel(&self.inner, x, y)
     }
 
     fn inner(&self) -> &Self {
@@ -404,17 +910,7 @@ impl<Buffer, P: Pixel> GenericImageView for ViewMut<Buffer, P>
             panic!("Image index {:?} out of bounds {:?}", (x, y), (self.inner.width, self.inner.height))
         }
 
-        let image = self.inner.samples.as_ref();
-        let base_index = self.inner.in_bounds_index(x, y, 0);
-        let channels = P::channel_count() as usize;
-
-        let mut buffer = [Zero::zero(); 256];
-        buffer.iter_mut().enumerate().take(channels).for_each(|(c, to)| {
-            let index = base_index + c*self.inner.channel_stride;
-            *to = image[index];
-        });
-
-        P::from_slice(&buffer[..channels]).clone()
+        gather_pixel(&self.inner, x, y)
     }
 
     fn inner(&self) -> &Self {
@@ -451,6 +947,100 @@ impl<Buffer, P: Pixel> GenericImage for ViewMut<Buffer, P>
     }
 }
 
+impl<Buffer, P: Pixel> Index<(u32, u32)> for View<Buffer, P>
+    where Buffer: AsRef<[P::Subpixel]>
+{
+    type Output = P;
+
+    /// Panics if the index is out of bounds or the samples are not pixel-packed.
+    fn index(&self, (x, y): (u32, u32)) -> &P {
+        if !self.inner.in_bounds(x, y, 0) {
+            panic!("Image index {:?} out of bounds {:?}", (x, y), (self.inner.width, self.inner.height))
+        }
+
+        assert_eq!(self.inner.channel_stride, 1,
+            "Can not index by pixel in a buffer that is not pixel-packed; index by (x, y, channel) instead");
+
+        let base_index = self.inner.in_bounds_index(x, y, 0);
+        let channel_count = P::channel_count() as usize;
+        P::from_slice(&self.inner.samples.as_ref()[base_index..base_index + channel_count])
+    }
+}
+
+impl<Buffer, P: Pixel> Index<(u32, u32)> for ViewMut<Buffer, P>
+    where Buffer: AsMut<[P::Subpixel]> + AsRef<[P::Subpixel]>,
+{
+    type Output = P;
+
+    fn index(&self, (x, y): (u32, u32)) -> &P {
+        if !self.inner.in_bounds(x, y, 0) {
+            panic!("Image index {:?} out of bounds {:?}", (x, y), (self.inner.width, self.inner.height))
+        }
+
+        let base_index = self.inner.in_bounds_index(x, y, 0);
+        let channel_count = P::channel_count() as usize;
+        P::from_slice(&self.inner.samples.as_ref()[base_index..base_index + channel_count])
+    }
+}
+
+impl<Buffer, P: Pixel> IndexMut<(u32, u32)> for ViewMut<Buffer, P>
+    where Buffer: AsMut<[P::Subpixel]> + AsRef<[P::Subpixel]>,
+{
+    fn index_mut(&mut self, (x, y): (u32, u32)) -> &mut P {
+        self.get_pixel_mut(x, y)
+    }
+}
+
+impl<Buffer, P: Pixel> Index<(u32, u32, u8)> for View<Buffer, P>
+    where Buffer: AsRef<[P::Subpixel]>
+{
+    type Output = P::Subpixel;
+
+    /// Indexes a single subpixel by channel. Unlike `Index<(u32, u32)>`, this works even on
+    /// non-`PixelPacked` layouts such as planar or padded buffers.
+    fn index(&self, (x, y, channel): (u32, u32, u8)) -> &P::Subpixel {
+        if !self.inner.in_bounds(x, y, channel) {
+            panic!("Image index {:?} out of bounds {:?}", (x, y, channel),
+                (self.inner.width, self.inner.height, self.inner.channels))
+        }
+
+        &self.inner.samples.as_ref()[self.inner.in_bounds_index(x, y, channel)]
+    }
+}
+
+impl<Buffer, P: Pixel> Index<(u32, u32, u8)> for ViewMut<Buffer, P>
+    where Buffer: AsMut<[P::Subpixel]> + AsRef<[P::Subpixel]>,
+{
+    type Output = P::Subpixel;
+
+    fn index(&self, (x, y, channel): (u32, u32, u8)) -> &P::Subpixel {
+        if !self.inner.in_bounds(x, y, channel) {
+            panic!("Image index {:?} out of bounds {:?}", (x, y, channel),
+                (self.inner.width, self.inner.height, self.inner.channels))
+        }
+
+        &self.inner.samples.as_ref()[self.inner.in_bounds_index(x, y, channel)]
+    }
+}
+
+impl<Buffer, P: Pixel> IndexMut<(u32, u32, u8)> for ViewMut<Buffer, P>
+    where Buffer: AsMut<[P::Subpixel]> + AsRef<[P::Subpixel]>,
+{
+    /// Mutably indexes a single subpixel by channel. Note that a `ViewMut` can only be
+    /// constructed through `as_view_mut`, which already requires `channel_stride == 1`, so in
+    /// practice this is always pixel-packed; see `Index<(u32, u32, u8)> for View` for the
+    /// general, non-mutable version that also accepts planar or padded layouts.
+    fn index_mut(&mut self, (x, y, channel): (u32, u32, u8)) -> &mut P::Subpixel {
+        if !self.inner.in_bounds(x, y, channel) {
+            panic!("Image index {:?} out of bounds {:?}", (x, y, channel),
+                (self.inner.width, self.inner.height, self.inner.channels))
+        }
+
+        let index = self.inner.in_bounds_index(x, y, channel);
+        &mut self.inner.samples.as_mut()[index]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -460,6 +1050,7 @@ mod tests {
     fn aliasing_view() {
        let buffer = FlatSamples {
            samples: &[42],
+           offset: 0,
            channels: 3,
            channel_stride: 0,
            width: 100,
@@ -480,6 +1071,7 @@ mod tests {
     fn mutable_view() {
         let mut buffer = FlatSamples {
             samples: [0; 18],
+            offset: 0,
             channels: 2,
             channel_stride: 1,
             width: 3,
@@ -491,7 +1083,6 @@ mod tests {
         {
             let mut view = buffer.as_view_mut::<LumaA<usize>>()
                 .expect("This should be a valid mutable buffer");
-            #[allow(deprecated)]
             let pixel_count = view.pixels_mut()
                 .enumerate()
                 .map(|(idx, (_, _, pixel))| *pixel = LumaA([2*idx, 2*idx + 1]))
@@ -503,4 +1094,92 @@ mod tests {
             .enumerate()
             .for_each(|(idx, sample)| assert_eq!(idx, *sample));
     }
+
+    #[test]
+    fn crop() {
+        let buffer = FlatSamples {
+            samples: &[0; 18][..],
+            offset: 0,
+            channels: 2,
+            channel_stride: 1,
+            width: 3,
+            width_stride: 2,
+            height: 3,
+            height_stride: 6,
+        };
+
+        let cropped = buffer.crop(1, 1, 2, 2)
+            .expect("Crop is within bounds");
+        assert_eq!(cropped.width, 2);
+        assert_eq!(cropped.height, 2);
+        assert_eq!(cropped.offset, buffer.in_bounds_index(1, 1, 0));
+        assert_eq!(cropped.max_index(), buffer.index(2, 2, 1));
+
+        assert!(buffer.crop(2, 2, 2, 2).is_none());
+    }
+
+    #[test]
+    fn planar_and_packed_constructors() {
+        let packed = FlatSamples::from_row_major_packed::<Rgb<u8>>(vec![0u8; 3*4*5], 4, 5);
+        assert!(!packed.has_aliased_samples());
+        assert_eq!(packed.channel_stride, 1);
+        assert_eq!(packed.width_stride, 3);
+        assert_eq!(packed.height_stride, 3*4);
+
+        let planar = FlatSamples::from_planar::<Rgb<u8>>(vec![0u8; 3*4*5], 4, 5);
+        assert!(!planar.has_aliased_samples());
+        assert_eq!(planar.channel_stride, 4*5);
+        assert_eq!(planar.width_stride, 1);
+        assert_eq!(planar.height_stride, 4);
+    }
+
+    #[test]
+    fn try_into_buffer_repacks_planar() {
+        // Two pixels, two channels: planar layout stores channel 0 of both pixels, then channel 1.
+        let planar = FlatSamples::from_planar::<LumaA<u8>>(vec![1, 2, 10, 20], 2, 1);
+
+        let buffer = planar.try_into_buffer::<LumaA<u8>>()
+            .expect("Unaliased planar buffer can always be repacked");
+
+        assert_eq!(*buffer.get_pixel(0, 0), LumaA([1, 10]));
+        assert_eq!(*buffer.get_pixel(1, 0), LumaA([2, 20]));
+    }
+
+    #[test]
+    fn try_into_buffer_reuses_row_major_packed() {
+        let packed = FlatSamples::from_row_major_packed::<LumaA<u8>>(vec![1, 10, 2, 20], 2, 1);
+
+        let buffer = packed.try_into_buffer::<LumaA<u8>>()
+            .expect("Row major packed buffer is already in normal form");
+
+        assert_eq!(*buffer.get_pixel(0, 0), LumaA([1, 10]));
+        assert_eq!(*buffer.get_pixel(1, 0), LumaA([2, 20]));
+    }
+
+    #[test]
+    fn indexing() {
+        let mut buffer = FlatSamples {
+            samples: [0; 18],
+            offset: 0,
+            channels: 2,
+            channel_stride: 1,
+            width: 3,
+            width_stride: 2,
+            height: 3,
+            height_stride: 6,
+        };
+
+        {
+            let mut view = buffer.as_view_mut::<LumaA<usize>>()
+                .expect("This should be a valid mutable buffer");
+            view[(1, 1)] = LumaA([42, 43]);
+            view[(2, 0, 1)] = 7;
+        }
+
+        let view = buffer.as_view::<LumaA<usize>>()
+            .expect("This should be a valid view");
+        assert_eq!(view[(1, 1)], LumaA([42, 43]));
+        assert_eq!(view[(2, 0, 0)], 0);
+        assert_eq!(view[(2, 0, 1)], 7);
+    }
 }